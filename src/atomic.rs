@@ -0,0 +1,16 @@
+//! The atomic integer type backing [`Unique`](crate::Unique)'s counter.
+//!
+//! [`core::sync::atomic::AtomicU64`] isn't available on every target: some
+//! 32-bit platforms (several riscv32 and MIPS targets, some Cortex-M chips)
+//! have no native 64-bit atomic instructions, so the standard library omits
+//! the type there entirely. Enabling the `portable` feature swaps the import
+//! below for the [`portable-atomic`](https://docs.rs/portable-atomic) crate's
+//! emulated `AtomicU64`, which provides the exact same `fetch_add` and
+//! `compare_exchange_weak` semantics (backed by a lock on targets that need
+//! one), so the rest of the crate doesn't need to know which one it's using.
+
+#[cfg(not(feature = "portable"))]
+pub use core::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "portable")]
+pub use portable_atomic::{AtomicU64, Ordering};
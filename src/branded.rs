@@ -0,0 +1,84 @@
+use core::marker::PhantomData;
+
+use crate::Unique;
+
+/// A zero-sized, compile-time-unique brand tied to an invariant lifetime `'id`.
+///
+/// Every call to [`Unique::scope()`] produces a [`Branded`] with a fresh `'id`
+/// that the compiler cannot unify with any other scope's `'id`. This makes
+/// two brands comparable (or combinable) only if they provably came from the
+/// same `scope` call, without any runtime id or atomic counter involved.
+///
+/// `Branded` is intentionally not [`Clone`] or [`Copy`]: it only exists to
+/// carry the `'id` brand, not to be stored or duplicated.
+pub struct Branded<'id> {
+    // `fn(&'id ()) -> &'id ()` is invariant in `'id`, so the compiler will not
+    // shrink or enlarge this lifetime to make two different brands match.
+    _marker: PhantomData<fn(&'id ()) -> &'id ()>,
+}
+
+impl Unique {
+    /// Run `f` with a fresh, compile-time-unique [`Branded`] brand.
+    ///
+    /// Unlike [`Unique::new()`], this performs no atomic increment and can
+    /// never panic from id exhaustion: uniqueness is enforced entirely by
+    /// the type system, via an invariant lifetime that cannot be confused
+    /// with the brand from a different `scope` call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unique_token::{Branded, Unique};
+    ///
+    /// fn same<'id>(_a: &Branded<'id>, _b: &Branded<'id>) {}
+    ///
+    /// Unique::scope(|brand| {
+    ///     same(&brand, &brand);
+    /// });
+    /// ```
+    ///
+    /// Brands from different `scope` calls carry different, incompatible
+    /// lifetimes, so this fails to compile:
+    ///
+    /// ```compile_fail
+    /// use unique_token::{Branded, Unique};
+    ///
+    /// fn same<'id>(_a: Branded<'id>, _b: Branded<'id>) {}
+    ///
+    /// Unique::scope(|a| {
+    ///     Unique::scope(|b| {
+    ///         same(a, b);
+    ///     });
+    /// });
+    /// ```
+    #[inline]
+    pub fn scope<F, R>(f: F) -> R
+    where
+        F: for<'id> FnOnce(Branded<'id>) -> R,
+    {
+        f(Branded {
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Branded;
+    use crate::Unique;
+
+    #[test]
+    fn test_scope_runs_closure() {
+        let result = Unique::scope(|_brand| 42);
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn test_brands_from_same_scope_match() {
+        fn same<'id>(_a: &Branded<'id>, _b: &Branded<'id>) {}
+
+        Unique::scope(|brand| {
+            same(&brand, &brand);
+        });
+    }
+}
@@ -1,8 +1,36 @@
 //! This crate provides a unique token type.
+//!
+//! # Features
+//!
+//! - `std` (default): enables the parts of the crate that need the standard
+//!   library, such as [`Unique::new_timestamped()`].
+//! - `portable`: backs [`Unique`]'s counter with `portable-atomic` instead of
+//!   [`core::sync::atomic::AtomicU64`], for targets without native 64-bit
+//!   atomics. See the [`atomic`] module for details.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 
-use std::sync::atomic::{AtomicU64, Ordering};
+mod atomic;
+mod branded;
+mod macros;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "std")]
+mod timestamped;
+
+use atomic::{AtomicU64, Ordering};
+
+pub use branded::Branded;
+#[cfg(feature = "std")]
+pub use pool::{PooledUnique, UniquePool};
+
+/// Items used by the [`unique_token!`] macro's expansion; not part of the
+/// public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::atomic::{AtomicU64, Ordering};
+}
 
 /// This type represents a unique token.
 ///
@@ -29,7 +57,8 @@ use std::sync::atomic::{AtomicU64, Ordering};
 /// # Implementation
 ///
 /// Each token is provided with a unique ID
-/// by incrementing a static [`AtomicU64`](std::sync::atomic::AtomicU64).
+/// by incrementing a static [`AtomicU64`](core::sync::atomic::AtomicU64)
+/// (or its `portable-atomic` equivalent, see the [`atomic`](crate::atomic) module).
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Unique(u64);
 
@@ -55,10 +84,29 @@ impl Unique {
         }
         Self(id)
     }
+
+    /// Build a token directly from its raw id.
+    ///
+    /// Only used internally by constructors that compute the id themselves,
+    /// such as [`Unique::new_timestamped()`], or that reconstruct a
+    /// previously-issued id, such as `UniquePool::acquire()`. Both of those
+    /// callers are only compiled with the `std` feature enabled.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn from_raw(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl Default for Unique {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl std::fmt::Debug for Unique {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+impl core::fmt::Debug for Unique {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         let len = (u64::BITS / 4) as usize;
         write!(fmt, "0x{:0len$X}", u64::from(self))
     }
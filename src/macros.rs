@@ -0,0 +1,106 @@
+/// Mint a brand-new token type with its own private counter.
+///
+/// Every [`Unique`](crate::Unique) in a program shares one global counter, so
+/// unrelated domains end up sharing the same `u64` id space and can't be
+/// told apart by the type system. `unique_token!` generates a distinct,
+/// zero-dependency token type, each with its own counter, so two generated
+/// types (or a generated type and [`Unique`](crate::Unique) itself) are
+/// never interchangeable, even if their ids happen to collide numerically.
+///
+/// The generated type supports the same `Clone`/`Copy`/`Eq`/`Hash`/`Debug`
+/// behavior, and the same overflow panic, as [`Unique`](crate::Unique).
+///
+/// # Examples
+///
+/// ```
+/// use unique_token::unique_token;
+///
+/// unique_token!(pub SessionId);
+/// unique_token!(pub RequestId);
+///
+/// let a = SessionId::new();
+/// let b = SessionId::new();
+/// assert_ne!(a, b);
+///
+/// // `SessionId` and `RequestId` are distinct types with independent
+/// // counters, so this would not type-check:
+/// // assert_ne!(a, RequestId::new());
+/// ```
+#[macro_export]
+macro_rules! unique_token {
+    ($vis:vis $name:ident) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $name(u64);
+
+        impl $name {
+            /// Create a new token.
+            ///
+            /// All tokens created by this function compare unequal.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if [`u64::MAX`] unique tokens have
+            /// been created.
+            #[inline]
+            $vis fn new() -> Self {
+                static NEXT_ID: $crate::__private::AtomicU64 = $crate::__private::AtomicU64::new(1);
+
+                let id = NEXT_ID.fetch_add(1, $crate::__private::Ordering::Relaxed);
+                if id == 0 {
+                    panic!("id overflow")
+                }
+                Self(id)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+                let len = (u64::BITS / 4) as usize;
+                write!(fmt, "0x{:0len$X}", u64::from(self))
+            }
+        }
+
+        impl From<&$name> for u64 {
+            #[inline]
+            fn from(token: &$name) -> u64 {
+                token.0
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_generated_type_counts_independently() {
+        crate::unique_token!(SessionId);
+        crate::unique_token!(RequestId);
+
+        let a = SessionId::new();
+        let b = SessionId::new();
+        assert_ne!(a, b);
+
+        // Both generated types start their own counter at 1.
+        assert_eq!(u64::from(&a), 1);
+        assert_eq!(u64::from(&RequestId::new()), 1);
+    }
+
+    #[test]
+    fn test_generated_type_supports_clone() {
+        crate::unique_token!(pub TestToken);
+
+        let token = TestToken::new();
+        assert_eq!(token, token.clone());
+    }
+
+    // `format!` needs `alloc`/`std`, so this is the one check that can't run
+    // in the `no_std` configuration the rest of this module targets.
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_generated_type_supports_debug() {
+        crate::unique_token!(pub TestToken);
+
+        let token = TestToken::new();
+        assert!(format!("{:?}", token).starts_with("0x"));
+    }
+}
@@ -0,0 +1,145 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::atomic::{AtomicU64, Ordering};
+use crate::Unique;
+
+/// Width of the timestamp field, in bits. 44 bits of milliseconds covers
+/// roughly 557 years from the Unix epoch, comfortably enough headroom that
+/// the remaining 20 bits can be spent mostly on randomness instead.
+const TIMESTAMP_BITS: u32 = 44;
+
+/// Width of the per-millisecond counter field, in bits.
+const COUNTER_BITS: u32 = 4;
+const COUNTER_MASK: u64 = (1 << COUNTER_BITS) - 1;
+
+/// Width of the trailing random field, in bits.
+const RANDOM_BITS: u32 = 64 - TIMESTAMP_BITS - COUNTER_BITS;
+const RANDOM_MASK: u64 = (1 << RANDOM_BITS) - 1;
+
+impl Unique {
+    /// Create a new token with a UUIDv8-style, time-ordered layout.
+    ///
+    /// Unlike [`Unique::new()`], whose ids are only unique within a single
+    /// process run, the id returned here packs:
+    ///
+    /// - the high 44 bits: a Unix millisecond timestamp, so tokens sort by
+    ///   creation time;
+    /// - the next 4 bits: a counter that increments for tokens created
+    ///   within the same millisecond, and resets when the millisecond
+    ///   advances;
+    /// - the low 16 bits: a random value, seeded once per process, so that
+    ///   tokens minted by different processes in the same millisecond (with
+    ///   the same counter sequence) usually still differ.
+    ///
+    /// With only 16 bits of per-process randomness, collisions between two
+    /// processes that start in the same millisecond are unlikely but not
+    /// negligible (a birthday-bound chance on the order of 1 in tens of
+    /// thousands for a handful of concurrently-starting processes); this is
+    /// best-effort collision *reduction* across processes/machines, not a
+    /// cryptographic or UUID-grade uniqueness guarantee.
+    ///
+    /// The counter is advanced through a single atomic compare-and-swap
+    /// loop, so concurrent callers within the same millisecond always get
+    /// distinct counter values. If the counter field saturates within a
+    /// millisecond, callers spin until the clock ticks over to the next
+    /// one.
+    ///
+    /// The resulting token still works with every existing conversion
+    /// ([`Debug`](std::fmt::Debug), [`u64::from`]) and compares equal only
+    /// to its own clones, exactly like a token from [`Unique::new()`].
+    pub fn new_timestamped() -> Self {
+        static STATE: AtomicU64 = AtomicU64::new(0);
+
+        let random = random_u16() as u64;
+
+        let mut prev = STATE.load(Ordering::Relaxed);
+        loop {
+            let prev_millis = prev >> COUNTER_BITS;
+            let now_millis = current_millis().max(prev_millis);
+
+            let counter = if now_millis > prev_millis {
+                0
+            } else {
+                (prev & COUNTER_MASK) + 1
+            };
+
+            if counter > COUNTER_MASK {
+                // The counter ran out for this millisecond; spin until the
+                // clock advances instead of ever reusing a counter value.
+                std::hint::spin_loop();
+                prev = STATE.load(Ordering::Relaxed);
+                continue;
+            }
+
+            let next = (now_millis << COUNTER_BITS) | counter;
+            match STATE.compare_exchange_weak(prev, next, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => {
+                    let id = (now_millis << (COUNTER_BITS + RANDOM_BITS))
+                        | (counter << RANDOM_BITS)
+                        | random;
+                    return Self::from_raw(id);
+                }
+                Err(actual) => prev = actual,
+            }
+        }
+    }
+}
+
+/// The current Unix time in milliseconds, truncated to [`TIMESTAMP_BITS`] bits.
+fn current_millis() -> u64 {
+    const TIMESTAMP_MASK: u64 = (1 << TIMESTAMP_BITS) - 1;
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the Unix epoch")
+        .as_millis() as u64;
+    millis & TIMESTAMP_MASK
+}
+
+/// A random value, generated once per process and reused for every
+/// subsequent call to [`Unique::new_timestamped()`].
+fn random_u16() -> u16 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::sync::OnceLock;
+
+    static SEED: OnceLock<u16> = OnceLock::new();
+    *SEED.get_or_init(|| {
+        let mut hasher = DefaultHasher::new();
+        SystemTime::now().hash(&mut hasher);
+        // Mix in the address of a stack value for extra entropy between
+        // processes that happen to start in the same millisecond.
+        let probe = 0u8;
+        (&probe as *const u8 as usize).hash(&mut hasher);
+        (hasher.finish() & RANDOM_MASK) as u16
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamped_tokens_are_unique() {
+        let x = Unique::new_timestamped();
+        let y = Unique::new_timestamped();
+        assert_ne!(x, y);
+    }
+
+    #[test]
+    fn test_timestamped_tokens_sort_by_creation_time() {
+        let x = Unique::new_timestamped();
+        let y = Unique::new_timestamped();
+        assert!(u64::from(&x) <= u64::from(&y));
+    }
+
+    #[test]
+    fn test_many_timestamped_tokens_across_counter_saturation_stay_unique() {
+        let tokens: Vec<_> = (0..1000).map(|_| Unique::new_timestamped()).collect();
+        for (i, a) in tokens.iter().enumerate() {
+            for b in &tokens[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+}